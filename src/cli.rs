@@ -0,0 +1,91 @@
+use crate::coverage;
+use crate::diagnostic::Diagnostic;
+use crate::doc_entry::function::FunctionDocEntry;
+use crate::links;
+
+/// Flags read from the command line that affect the post-parse passes added across this backlog.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Args {
+    /// `--coverage`: print a documentation-coverage report instead of generating docs.
+    pub coverage: bool,
+    /// `--coverage-json`: render the `--coverage` report as JSON. Implies `coverage`.
+    pub coverage_json: bool,
+    /// `--coverage-include-hidden`: count `private`/`ignore` entries towards coverage totals.
+    pub coverage_include_hidden: bool,
+    /// `--skip-code-block-checks`: don't validate `lua`/`luau` fenced code blocks in descriptions.
+    pub skip_code_block_checks: bool,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = S>, S: AsRef<str>>(raw: I) -> Self {
+        let mut args = Self::default();
+
+        for flag in raw {
+            match flag.as_ref() {
+                "--coverage" => args.coverage = true,
+                "--coverage-json" => {
+                    args.coverage = true;
+                    args.coverage_json = true;
+                }
+                "--coverage-include-hidden" => args.coverage_include_hidden = true,
+                "--skip-code-block-checks" => args.skip_code_block_checks = true,
+                _ => {}
+            }
+        }
+
+        args
+    }
+}
+
+/// Runs every post-parse pass added across this backlog, in the order moonwave's pipeline would
+/// run them: link resolution first (it needs every entry at once), then the per-entry checks.
+///
+/// If `--coverage` was passed, returns the rendered report instead of generating docs, mirroring
+/// rustdoc's `--show-coverage`.
+pub fn run(args: &Args, entries: &mut [FunctionDocEntry]) -> (Vec<Diagnostic>, Option<String>) {
+    let mut diagnostics = links::resolve_links(entries);
+
+    for entry in entries.iter() {
+        diagnostics.extend(entry.check_code_blocks(args.skip_code_block_checks));
+        diagnostics.extend(entry.lint_desc());
+    }
+
+    if !args.coverage {
+        return (diagnostics, None);
+    }
+
+    let report = coverage::collect_coverage(entries, args.coverage_include_hidden);
+    let rendered = if args.coverage_json {
+        serde_json::to_string_pretty(&report).unwrap_or_default()
+    } else {
+        coverage::render_table(&report)
+    };
+
+    (diagnostics, Some(rendered))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage_json_flag_implies_coverage() {
+        let args = Args::parse(["--coverage-json"]);
+        assert!(args.coverage);
+        assert!(args.coverage_json);
+    }
+
+    #[test]
+    fn run_renders_a_table_by_default_when_coverage_is_requested() {
+        let args = Args::parse(["--coverage"]);
+        let (_, report) = run(&args, &mut []);
+        assert!(report.unwrap().contains("Total"));
+    }
+
+    #[test]
+    fn run_skips_the_report_when_coverage_was_not_requested() {
+        let args = Args::parse(Vec::<String>::new());
+        let (_, report) = run(&args, &mut []);
+        assert!(report.is_none());
+    }
+}
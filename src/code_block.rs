@@ -0,0 +1,138 @@
+use crate::diagnostic::Diagnostic;
+use crate::doc_comment::DocComment;
+
+/// A fenced code block extracted from a doc comment, along with its byte span relative to the
+/// text it was found in (not the whole comment).
+struct FencedBlock<'a> {
+    info_string: &'a str,
+    code: &'a str,
+    start: usize,
+    end: usize,
+}
+
+/// Validates every `lua`/`luau` fenced code block found in `desc` (and in `@error`/`@param`
+/// prose) by running it through a Luau parser, modeled on rustdoc's check-code-block-syntax pass.
+///
+/// Never aborts early: every broken block in the text is collected and reported together.
+pub fn check_luau_blocks(text: &str, source: &DocComment) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let base = source.offset_of(text);
+
+    for block in find_fenced_blocks(text) {
+        if block.info_string != "lua" && block.info_string != "luau" {
+            continue;
+        }
+
+        if let Err(errors) = full_moon::parse(block.code) {
+            for error in errors {
+                let (start, end) = translate_error_span(block.code, block.start, &error);
+                diagnostics.push(source.diagnostic_at(
+                    base + start,
+                    base + end,
+                    &format!("Luau code block failed to parse: {error}"),
+                ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Translates a parser error's line/column into a byte span within `text`, anchored at
+/// `block_start`, so the diagnostic underlines the actual failing line rather than the whole block.
+fn translate_error_span(
+    code: &str,
+    block_start: usize,
+    error: &full_moon::Error,
+) -> (usize, usize) {
+    let (line, column) = error.position();
+    let mut offset = block_start;
+
+    for code_line in code.split('\n').take(line.saturating_sub(1)) {
+        offset += code_line.len() + 1;
+    }
+
+    let failing_line = code.split('\n').nth(line.saturating_sub(1)).unwrap_or("");
+    let start = offset + column.saturating_sub(1).min(failing_line.len());
+    let end = offset + failing_line.len();
+
+    (start, end.max(start + 1))
+}
+
+fn find_fenced_blocks(text: &str) -> Vec<FencedBlock<'_>> {
+    let mut blocks = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(open_rel) = text[search_from..].find("```") {
+        let open = search_from + open_rel;
+        let info_start = open + 3;
+        let Some(line_end_rel) = text[info_start..].find('\n') else {
+            break;
+        };
+        let line_end = info_start + line_end_rel;
+        let info_string = text[info_start..line_end].trim();
+        let code_start = line_end + 1;
+
+        let Some(close_rel) = text[code_start..].find("```") else {
+            break;
+        };
+        let code_end = code_start + close_rel;
+
+        blocks.push(FencedBlock {
+            info_string,
+            code: &text[code_start..code_end],
+            start: code_start,
+            end: code_end,
+        });
+
+        search_from = code_end + 3;
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_luau_blocks_produce_no_diagnostics() {
+        let source = DocComment::new("");
+        let text = "```luau\nlocal x = 1\n```";
+
+        assert!(check_luau_blocks(text, &source).is_empty());
+    }
+
+    #[test]
+    fn invalid_luau_blocks_are_reported() {
+        let source = DocComment::new("");
+        let text = "```luau\nlocal x = (\n```";
+
+        let diagnostics = check_luau_blocks(text, &source);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("failed to parse"));
+    }
+
+    #[test]
+    fn the_diagnostic_is_anchored_at_the_failing_line_not_the_whole_block() {
+        let source = DocComment::new("");
+        let text = "```luau\nlocal ok = 1\nlocal bad = (\n```";
+
+        let diagnostics = check_luau_blocks(text, &source);
+
+        assert_eq!(diagnostics.len(), 1);
+        let block_start = text.find("local ok").unwrap();
+        let failing_line_start = text.find("local bad").unwrap();
+        assert!(diagnostics[0].start >= failing_line_start);
+        assert!(diagnostics[0].start > block_start);
+    }
+
+    #[test]
+    fn blocks_with_an_unrecognized_info_string_are_skipped() {
+        let source = DocComment::new("");
+        let text = "```json\n{\n```";
+
+        assert!(check_luau_blocks(text, &source).is_empty());
+    }
+}
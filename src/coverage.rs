@@ -0,0 +1,202 @@
+use serde::Serialize;
+
+use crate::doc_entry::function::FunctionDocEntry;
+
+/// Documentation coverage counters for a single class (`within`) or for the
+/// whole crate, modeled on rustdoc's `--show-coverage` pass.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct CoverageStats {
+    /// Total number of entries that were counted towards this total.
+    pub examined: usize,
+    /// Entries with a non-empty `desc`.
+    pub documented: usize,
+    /// Params across all examined entries with an empty `ParamTag` description.
+    pub undocumented_param_descs: usize,
+    /// `@return` tags across all examined entries with empty description text.
+    pub undocumented_returns: usize,
+    /// Functions with no `@return` tag at all.
+    ///
+    /// This counts every return-less entry, including ones that are legitimately void (e.g. a
+    /// setter): moonwave doc comments have no dedicated way to mark "intentionally returns
+    /// nothing" on a function entry, so there's no signal to exclude those by. An explicit
+    /// `@return void` costs nothing to write and is the convention this metric expects.
+    pub missing_returns: usize,
+}
+
+impl CoverageStats {
+    /// Percentage of `examined` entries that are `documented`, `0.0` if nothing was examined.
+    pub fn percentage(&self) -> f64 {
+        if self.examined == 0 {
+            return 0.0;
+        }
+
+        (self.documented as f64 / self.examined as f64) * 100.0
+    }
+
+    fn add(&mut self, other: &CoverageStats) {
+        self.examined += other.examined;
+        self.documented += other.documented;
+        self.undocumented_param_descs += other.undocumented_param_descs;
+        self.undocumented_returns += other.undocumented_returns;
+        self.missing_returns += other.missing_returns;
+    }
+}
+
+/// Coverage stats scoped to a single `within` (class).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClassCoverage {
+    pub within: String,
+    pub stats: CoverageStats,
+}
+
+/// Crate-wide coverage report, suitable for serializing directly as the `--coverage-json` output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageReport {
+    pub classes: Vec<ClassCoverage>,
+    pub total: CoverageStats,
+}
+
+/// Walks every entry and tallies up documentation coverage, modeled on rustdoc's doc-coverage pass.
+///
+/// Entries flagged `ignore` or `private` are excluded from the denominator unless `include_hidden` is set.
+pub fn collect_coverage(entries: &[FunctionDocEntry], include_hidden: bool) -> CoverageReport {
+    let mut by_class: Vec<ClassCoverage> = Vec::new();
+
+    for entry in entries {
+        if (entry.ignore || entry.private) && !include_hidden {
+            continue;
+        }
+
+        let mut stats = CoverageStats {
+            examined: 1,
+            documented: usize::from(!entry.desc.is_empty()),
+            ..CoverageStats::default()
+        };
+
+        for param in &entry.params {
+            if param.desc.is_empty() {
+                stats.undocumented_param_descs += 1;
+            }
+        }
+
+        for return_tag in &entry.returns {
+            if return_tag.desc.is_empty() {
+                stats.undocumented_returns += 1;
+            }
+        }
+
+        if entry.returns.is_empty() {
+            stats.missing_returns += 1;
+        }
+
+        match by_class.iter_mut().find(|class| class.within == entry.within) {
+            Some(class) => class.stats.add(&stats),
+            None => by_class.push(ClassCoverage {
+                within: entry.within.clone(),
+                stats,
+            }),
+        }
+    }
+
+    let mut total = CoverageStats::default();
+    for class in &by_class {
+        total.add(&class.stats);
+    }
+
+    CoverageReport {
+        classes: by_class,
+        total,
+    }
+}
+
+/// Renders a report as the human-readable table printed by `--coverage`.
+pub fn render_table(report: &CoverageReport) -> String {
+    let mut out = String::new();
+
+    for class in &report.classes {
+        out.push_str(&format!(
+            "{:<30} {:>4}/{:<4} ({:>5.1}%)\n",
+            class.within, class.stats.documented, class.stats.examined, class.stats.percentage()
+        ));
+    }
+
+    out.push_str(&format!(
+        "{:<30} {:>4}/{:<4} ({:>5.1}%)\n",
+        "Total", report.total.documented, report.total.examined, report.total.percentage()
+    ));
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::doc_comment::DocComment;
+    use crate::doc_entry::function::FunctionType;
+    use crate::tags::{ReturnTag, TagToken};
+
+    fn token(source: &DocComment) -> TagToken<'_> {
+        TagToken {
+            source,
+            start: 0,
+            end: 0,
+        }
+    }
+
+    fn entry<'a>(source: &'a DocComment, desc: &str, within: &str) -> FunctionDocEntry<'a> {
+        FunctionDocEntry {
+            name: "example".to_string(),
+            desc: desc.to_string(),
+            within: within.to_string(),
+            params: Vec::new(),
+            returns: Vec::new(),
+            tags: Vec::new(),
+            errors: Vec::new(),
+            function_type: FunctionType::Static,
+            realm: BTreeSet::new(),
+            private: false,
+            unreleased: false,
+            yields: false,
+            ignore: false,
+            since: None,
+            deprecated: None,
+            resolved_links: Vec::new(),
+            overloads: Vec::new(),
+            source,
+        }
+    }
+
+    #[test]
+    fn counts_documented_missing_and_empty_returns() {
+        let source = DocComment::new("");
+        let mut documented = entry(&source, "Does a thing.", "Widget");
+        documented.returns.push(ReturnTag {
+            desc: String::new(),
+            token: token(&source),
+        });
+        let undocumented = entry(&source, "", "Widget");
+
+        let report = collect_coverage(&[documented, undocumented], false);
+
+        assert_eq!(report.total.examined, 2);
+        assert_eq!(report.total.documented, 1);
+        assert_eq!(report.total.undocumented_returns, 1);
+        assert_eq!(report.total.missing_returns, 1);
+        assert_eq!(report.total.percentage(), 50.0);
+    }
+
+    #[test]
+    fn excludes_hidden_entries_unless_requested() {
+        let source = DocComment::new("");
+
+        let mut hidden = entry(&source, "", "Widget");
+        hidden.private = true;
+        assert_eq!(collect_coverage(std::slice::from_ref(&hidden), false).total.examined, 0);
+
+        let mut hidden = entry(&source, "", "Widget");
+        hidden.private = true;
+        assert_eq!(collect_coverage(&[hidden], true).total.examined, 1);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// A single diagnostic message anchored to a byte range within a `DocComment`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(start: usize, end: usize, message: impl Into<String>) -> Self {
+        Self {
+            start,
+            end,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// One or more diagnostics produced while parsing a single doc entry.
+///
+/// `FunctionDocEntry::parse` and friends return this (rather than a single `Diagnostic`) so that
+/// every problem found in one entry can be reported together instead of failing at the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl From<Vec<Diagnostic>> for Diagnostics {
+    fn from(diagnostics: Vec<Diagnostic>) -> Self {
+        Self(diagnostics)
+    }
+}
+
+impl From<Diagnostic> for Diagnostics {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Self(vec![diagnostic])
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diagnostic in &self.0 {
+            writeln!(f, "{diagnostic}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostics {}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use crate::diagnostic::Diagnostic;
+
+/// A single buffered diagnostic, keyed by the source span it points at.
+struct Buffered {
+    diagnostic: Diagnostic,
+    span: (usize, usize),
+}
+
+/// Buffers diagnostics keyed by source span so that, once flushed, a single root cause surfaces
+/// as one diagnostic instead of several overlapping ones.
+///
+/// Mirrors the move-error buffering strategy used by rustc's borrow checker: later diagnostics
+/// whose span is a strict subset of an already-buffered span replace it (a narrower location is
+/// more specific), a later diagnostic whose span contains an already-buffered span is dropped,
+/// and a later diagnostic with a disjoint span is buffered alongside the existing ones.
+#[derive(Default)]
+pub struct DiagnosticCollector {
+    buffered: BTreeMap<(usize, usize), Buffered>,
+}
+
+impl DiagnosticCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffers `diagnostic`, anchored at byte range `span` within the entry's source.
+    ///
+    /// If an already-buffered diagnostic's span contains `span`, the new, more specific
+    /// diagnostic replaces it. If `span` contains an already-buffered diagnostic's span, the
+    /// existing, more specific one is kept and this report is dropped.
+    pub fn report(&mut self, span: (usize, usize), diagnostic: Diagnostic) {
+        let superseded_by_existing = self
+            .buffered
+            .values()
+            .any(|existing| contains(span, existing.span) && existing.span != span);
+
+        if superseded_by_existing {
+            return;
+        }
+
+        self.buffered
+            .retain(|_, existing| !contains(existing.span, span) || existing.span == span);
+
+        self.buffered.insert(span, Buffered { diagnostic, span });
+    }
+
+    /// Flushes the deduplicated set of diagnostics in stable, span-ordered order.
+    pub fn flush(self) -> Vec<Diagnostic> {
+        self.buffered.into_values().map(|b| b.diagnostic).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffered.is_empty()
+    }
+}
+
+/// Whether byte range `outer` contains `inner` (equal ranges count as contained).
+fn contains(outer: (usize, usize), inner: (usize, usize)) -> bool {
+    outer.0 <= inner.0 && inner.1 <= outer.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(message: &str) -> Diagnostic {
+        Diagnostic::new(0, 0, message)
+    }
+
+    #[test]
+    fn a_narrower_later_span_supersedes_a_broader_earlier_one() {
+        let mut collector = DiagnosticCollector::new();
+        collector.report((0, 20), diagnostic("broad"));
+        collector.report((5, 10), diagnostic("narrow"));
+
+        let flushed = collector.flush();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].message, "narrow");
+    }
+
+    #[test]
+    fn a_broader_later_span_does_not_override_an_existing_narrower_one() {
+        let mut collector = DiagnosticCollector::new();
+        collector.report((5, 10), diagnostic("narrow"));
+        collector.report((0, 20), diagnostic("broad"));
+
+        let flushed = collector.flush();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].message, "narrow");
+    }
+
+    #[test]
+    fn disjoint_spans_are_both_kept() {
+        let mut collector = DiagnosticCollector::new();
+        collector.report((0, 5), diagnostic("first"));
+        collector.report((10, 15), diagnostic("second"));
+
+        assert_eq!(collector.flush().len(), 2);
+    }
+
+    #[test]
+    fn flush_order_is_stable_and_span_sorted() {
+        let mut collector = DiagnosticCollector::new();
+        collector.report((10, 15), diagnostic("second"));
+        collector.report((0, 5), diagnostic("first"));
+
+        let flushed = collector.flush();
+        assert_eq!(flushed[0].message, "first");
+        assert_eq!(flushed[1].message, "second");
+    }
+}
@@ -0,0 +1,29 @@
+use crate::diagnostic::Diagnostic;
+
+/// The raw `--[=[ ... ]=]` (or `---`) comment block a doc entry was parsed from, kept around so
+/// diagnostics produced long after parsing can still be anchored back to the original source.
+#[derive(Debug, PartialEq)]
+pub struct DocComment {
+    pub text: String,
+}
+
+impl DocComment {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+
+    /// Builds a `Diagnostic` anchored at byte range `[start, end)` within this comment's text.
+    pub fn diagnostic_at(&self, start: usize, end: usize, message: &str) -> Diagnostic {
+        Diagnostic::new(start, end, message)
+    }
+
+    /// The byte offset within this comment's full text where `desc` begins, or `0` if it can't be
+    /// found (e.g. in tests that build a `desc` independently of `source`).
+    ///
+    /// `desc` is tag-stripped and therefore no longer aligned with `self.text`, so a pass that
+    /// finds a span within `desc` must add this offset before calling `diagnostic_at`, or the
+    /// diagnostic ends up anchored at the wrong place in the original comment.
+    pub fn offset_of(&self, desc: &str) -> usize {
+        self.text.find(desc).unwrap_or(0)
+    }
+}
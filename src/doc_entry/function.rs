@@ -1,10 +1,13 @@
 use std::collections::BTreeSet;
 
 use crate::{
-    diagnostic::Diagnostics,
+    diagnostic::{Diagnostic, Diagnostics},
     doc_comment::DocComment,
     realm::Realm,
-    tags::{CustomTag, DeprecatedTag, ErrorTag, ParamTag, ReturnTag, Tag},
+    tags::{
+        CustomTag, DeprecatedTag, ErrorTag, OverloadBody, OverloadTag, ParamTag, ReturnTag, Tag,
+        TagToken,
+    },
 };
 use serde::Serialize;
 
@@ -42,6 +45,16 @@ pub struct FunctionDocEntry<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<DeprecatedTag<'a>>,
 
+    /// Intra-doc references (`[Class.method]`, `[Class:method]`, bare `[name]`) found in `desc` and
+    /// resolved against the other parsed entries, so renderers can hyperlink them directly. A bare
+    /// name resolves only to a function or method of this entry's class; class names are not.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resolved_links: Vec<crate::links::ResolvedLink>,
+
+    /// Alternative signatures declared with `@overload`, in the order they were written.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub overloads: Vec<OverloadTag<'a>>,
+
     #[serde(skip)]
     pub source: &'a DocComment,
 }
@@ -76,9 +89,12 @@ impl<'a> FunctionDocEntry<'a> {
             unreleased: false,
             yields: false,
             ignore: false,
+            resolved_links: Vec::new(),
+            overloads: Vec::new(),
         };
 
-        let mut unused_tags = Vec::new();
+        let mut diagnostics = crate::diagnostic_collector::DiagnosticCollector::new();
+        let mut pending_overloads = Vec::new();
 
         for tag in tags {
             match tag {
@@ -100,19 +116,212 @@ impl<'a> FunctionDocEntry<'a> {
                 Tag::Client(_) => {
                     doc_entry.realm.insert(Realm::Client);
                 }
-                _ => unused_tags.push(tag),
+
+                Tag::Overload(token, body) => {
+                    let parsed = OverloadBody::parse(&body, &token);
+                    pending_overloads.push((token, parsed));
+                }
+
+                _ => {
+                    let span = tag.span();
+                    diagnostics.report(
+                        span,
+                        tag.diagnostic("This tag is unused by function doc entries."),
+                    );
+                }
             }
         }
 
-        if !unused_tags.is_empty() {
-            let mut diagnostics = Vec::new();
-            for tag in unused_tags {
-                diagnostics.push(tag.diagnostic("This tag is unused by function doc entries."));
-            }
+        resolve_overloads(pending_overloads, &mut doc_entry.overloads, &mut diagnostics);
 
-            return Err(Diagnostics::from(diagnostics));
+        if !diagnostics.is_empty() {
+            return Err(Diagnostics::from(diagnostics.flush()));
         }
 
         Ok(doc_entry)
     }
+
+    /// Validates every `lua`/`luau` fenced code block in this entry's `desc`, `@error`, and
+    /// `@param` prose. Pass `skip_code_block_checks` to opt out entirely.
+    pub fn check_code_blocks(&self, skip_code_block_checks: bool) -> Vec<Diagnostic> {
+        if skip_code_block_checks {
+            return Vec::new();
+        }
+
+        let mut diagnostics = crate::code_block::check_luau_blocks(&self.desc, self.source);
+
+        for error in &self.errors {
+            diagnostics.extend(crate::code_block::check_luau_blocks(
+                &error.desc,
+                self.source,
+            ));
+        }
+
+        for param in &self.params {
+            diagnostics.extend(crate::code_block::check_luau_blocks(
+                &param.desc,
+                self.source,
+            ));
+        }
+
+        diagnostics
+    }
+
+    /// Lints this entry's `desc` for bare URLs and unbalanced HTML tags. Both are warnings and
+    /// never prevent the entry from being used.
+    pub fn lint_desc(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = crate::lints::lint_bare_urls(&self.desc, self.source);
+        diagnostics.extend(crate::lints::lint_unbalanced_html(&self.desc, self.source));
+        diagnostics
+    }
+}
+
+/// Resolves the `@overload` tags collected while parsing a single entry.
+///
+/// A bare-name body (see `OverloadBody::parse`) references a signature declared by an earlier
+/// `@overload` tag on the same entry; this replaces it with that signature. Duplicate names and
+/// references to a name that was never declared are reported rather than silently merged.
+fn resolve_overloads<'a>(
+    pending: Vec<(TagToken<'a>, OverloadBody<'a>)>,
+    overloads: &mut Vec<OverloadTag<'a>>,
+    diagnostics: &mut crate::diagnostic_collector::DiagnosticCollector,
+) {
+    let mut named: std::collections::HashMap<String, OverloadTag<'a>> =
+        std::collections::HashMap::new();
+    let mut seen_names = BTreeSet::new();
+
+    for (token, parsed) in pending {
+        let name = match &parsed {
+            OverloadBody::Resolved(tag) => tag.name.clone(),
+            OverloadBody::Reference { name, .. } => name.clone(),
+        };
+
+        if let Some(name) = &name {
+            if !seen_names.insert(name.clone()) {
+                diagnostics.report(
+                    token.span(),
+                    token.diagnostic(&format!(
+                        "Duplicate `@overload` name `{name}`; each overload must have a unique name."
+                    )),
+                );
+                continue;
+            }
+        }
+
+        match parsed {
+            OverloadBody::Resolved(tag) => {
+                if let Some(name) = &tag.name {
+                    named.insert(name.clone(), tag.clone());
+                }
+                overloads.push(tag);
+            }
+            OverloadBody::Reference { reference, .. } => match named.get(&reference) {
+                Some(target) => {
+                    let mut resolved = target.clone();
+                    resolved.name = name;
+                    resolved.token = token;
+                    overloads.push(resolved);
+                }
+                None => diagnostics.report(
+                    token.span(),
+                    token.diagnostic(&format!(
+                        "Unknown `@overload` reference `{reference}`; no overload named `{reference}` has been declared."
+                    )),
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(source: &DocComment) -> TagToken<'_> {
+        TagToken {
+            source,
+            start: 0,
+            end: 1,
+        }
+    }
+
+    fn args<'a>(source: &'a DocComment, tags: Vec<Tag<'a>>) -> DocEntryParseArguments<'a> {
+        DocEntryParseArguments {
+            name: "example".to_string(),
+            desc: String::new(),
+            within: Some("Widget".to_string()),
+            tags,
+            source,
+        }
+    }
+
+    #[test]
+    fn collects_an_inline_overload() {
+        let source = DocComment::new("");
+        let body = "(count: number): boolean".to_string();
+        let entry = FunctionDocEntry::parse(
+            args(&source, vec![Tag::Overload(token(&source), body)]),
+            FunctionType::Static,
+        )
+        .unwrap();
+
+        assert_eq!(entry.overloads.len(), 1);
+        assert_eq!(entry.overloads[0].signature, "(count: number): boolean");
+    }
+
+    #[test]
+    fn resolves_a_named_reference() {
+        let source = DocComment::new("");
+        let named = "name: withCount\n(count: number): boolean".to_string();
+        let reference = "withCount".to_string();
+        let entry = FunctionDocEntry::parse(
+            args(
+                &source,
+                vec![
+                    Tag::Overload(token(&source), named),
+                    Tag::Overload(token(&source), reference),
+                ],
+            ),
+            FunctionType::Static,
+        )
+        .unwrap();
+
+        assert_eq!(entry.overloads.len(), 2);
+        assert_eq!(entry.overloads[1].signature, "(count: number): boolean");
+    }
+
+    #[test]
+    fn reports_an_unknown_reference() {
+        let source = DocComment::new("");
+        let err = FunctionDocEntry::parse(
+            args(
+                &source,
+                vec![Tag::Overload(token(&source), "missing".to_string())],
+            ),
+            FunctionType::Static,
+        )
+        .unwrap_err();
+
+        assert!(err.0[0].message.contains("Unknown"));
+    }
+
+    #[test]
+    fn reports_duplicate_overload_names() {
+        let source = DocComment::new("");
+        let first = "name: dup\n(a: number)".to_string();
+        let second = "name: dup\n(b: string)".to_string();
+        let err = FunctionDocEntry::parse(
+            args(
+                &source,
+                vec![
+                    Tag::Overload(token(&source), first),
+                    Tag::Overload(token(&source), second),
+                ],
+            ),
+            FunctionType::Static,
+        )
+        .unwrap_err();
+
+        assert!(err.0[0].message.contains("Duplicate"));
+    }
 }
@@ -0,0 +1,14 @@
+pub mod function;
+
+use crate::doc_comment::DocComment;
+use crate::tags::Tag;
+
+/// The pieces a specific doc-entry kind (`FunctionDocEntry`, etc.) needs out of a parsed comment
+/// block before it can build itself.
+pub struct DocEntryParseArguments<'a> {
+    pub name: String,
+    pub desc: String,
+    pub within: Option<String>,
+    pub tags: Vec<Tag<'a>>,
+    pub source: &'a DocComment,
+}
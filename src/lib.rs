@@ -0,0 +1,11 @@
+pub mod cli;
+pub mod code_block;
+pub mod coverage;
+pub mod diagnostic;
+pub mod diagnostic_collector;
+pub mod doc_comment;
+pub mod doc_entry;
+pub mod links;
+pub mod lints;
+pub mod realm;
+pub mod tags;
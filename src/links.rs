@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::diagnostic::Diagnostic;
+use crate::doc_entry::function::{FunctionDocEntry, FunctionType};
+
+/// A `[Class.method]` / `[Class:method]` / bare `[name]` reference that was resolved against the
+/// rest of the crate's parsed entries. A bare name resolves only to a function or method of the
+/// referring entry's own class; a class name on its own is not resolved.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResolvedLink {
+    /// The raw bracketed text as it appeared in the source desc, e.g. `Class:method`.
+    pub raw: String,
+    /// The `within.name` path the reference resolves to.
+    pub target: String,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RefKind {
+    Dotted,
+    Coloned,
+    Bare,
+}
+
+struct ParsedRef {
+    raw: String,
+    within: Option<String>,
+    name: String,
+    kind: RefKind,
+    start: usize,
+    end: usize,
+}
+
+/// Runs after every entry has been parsed: resolves `[...]` references in each entry's `desc`
+/// against the other entries, recording resolved ones in each entry's `resolved_links` (the
+/// `desc` text itself is left unchanged) and collecting diagnostics for
+/// references that don't exist or are ambiguous between a function and a method.
+pub fn resolve_links(entries: &mut [FunctionDocEntry]) -> Vec<Diagnostic> {
+    let mut by_static: HashMap<(String, String), usize> = HashMap::new();
+    let mut by_method: HashMap<(String, String), usize> = HashMap::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let key = (entry.within.clone(), entry.name.clone());
+        match entry.function_type {
+            FunctionType::Static => {
+                by_static.insert(key, index);
+            }
+            FunctionType::Method => {
+                by_method.insert(key, index);
+            }
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    let mut resolutions: Vec<(usize, ResolvedLink)> = Vec::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        let base = entry.source.offset_of(&entry.desc);
+
+        for reference in find_references(&entry.desc) {
+            match resolve_one(&reference, &entry.within, &by_static, &by_method) {
+                Ok(Some((target_within, target_name))) => resolutions.push((
+                    index,
+                    ResolvedLink {
+                        raw: reference.raw.clone(),
+                        target: format!("{target_within}.{target_name}"),
+                    },
+                )),
+                Ok(None) => diagnostics.push(
+                    entry
+                        .source
+                        .diagnostic_at(base + reference.start, base + reference.end, &format!(
+                            "Unresolved intra-doc link `[{}]`: no matching function or method was found.",
+                            reference.raw
+                        )),
+                ),
+                Err(ambiguous) => diagnostics.push(
+                    entry.source.diagnostic_at(
+                        base + reference.start,
+                        base + reference.end,
+                        &format!(
+                            "Ambiguous intra-doc link `[{}]`: both a function and a method named `{}` exist, use `.` or `:` to disambiguate.",
+                            reference.raw, ambiguous
+                        ),
+                    ),
+                ),
+            }
+        }
+    }
+
+    for (index, link) in resolutions {
+        entries[index].resolved_links.push(link);
+    }
+
+    diagnostics
+}
+
+fn resolve_one(
+    reference: &ParsedRef,
+    default_within: &str,
+    by_static: &HashMap<(String, String), usize>,
+    by_method: &HashMap<(String, String), usize>,
+) -> Result<Option<(String, String)>, String> {
+    let within = reference
+        .within
+        .clone()
+        .unwrap_or_else(|| default_within.to_string());
+    let key = (within.clone(), reference.name.clone());
+
+    match reference.kind {
+        RefKind::Dotted => Ok(by_static.get(&key).map(|_| (within, reference.name.clone()))),
+        RefKind::Coloned => Ok(by_method.get(&key).map(|_| (within, reference.name.clone()))),
+        RefKind::Bare => {
+            let is_static = by_static.contains_key(&key);
+            let is_method = by_method.contains_key(&key);
+            if is_static && is_method {
+                Err(reference.name.clone())
+            } else if is_static || is_method {
+                Ok(Some((within, reference.name.clone())))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Scans `desc` for `[...]` references, skipping anything inside fenced (```) or inline (`) code spans.
+fn find_references(desc: &str) -> Vec<ParsedRef> {
+    let mut refs = Vec::new();
+    let mut in_fence = false;
+    let mut in_inline_code = false;
+    let mut i = 0;
+
+    while i < desc.len() {
+        if desc[i..].starts_with("```") {
+            in_fence = !in_fence;
+            i += 3;
+            continue;
+        }
+
+        let Some(ch) = desc[i..].chars().next() else {
+            break;
+        };
+
+        if !in_fence && ch == '`' {
+            in_inline_code = !in_inline_code;
+            i += ch.len_utf8();
+            continue;
+        }
+
+        if !in_fence && !in_inline_code && ch == '[' {
+            if let Some(close) = desc[i + 1..].find(']').map(|p| p + i + 1) {
+                let raw = &desc[i + 1..close];
+                let is_markdown_link = desc[close + 1..].starts_with('(');
+                if !is_markdown_link && !raw.is_empty() && !raw.contains(' ') && !raw.contains('\n')
+                {
+                    if let Some(parsed) = parse_reference(raw, i, close + 1) {
+                        refs.push(parsed);
+                    }
+                }
+                i = close + 1;
+                continue;
+            }
+        }
+
+        i += ch.len_utf8();
+    }
+
+    refs
+}
+
+fn parse_reference(raw: &str, start: usize, end: usize) -> Option<ParsedRef> {
+    if let Some((within, name)) = raw.split_once('.') {
+        return Some(ParsedRef {
+            raw: raw.to_string(),
+            within: Some(within.to_string()),
+            name: name.to_string(),
+            kind: RefKind::Dotted,
+            start,
+            end,
+        });
+    }
+
+    if let Some((within, name)) = raw.split_once(':') {
+        return Some(ParsedRef {
+            raw: raw.to_string(),
+            within: Some(within.to_string()),
+            name: name.to_string(),
+            kind: RefKind::Coloned,
+            start,
+            end,
+        });
+    }
+
+    Some(ParsedRef {
+        raw: raw.to_string(),
+        within: None,
+        name: raw.to_string(),
+        kind: RefKind::Bare,
+        start,
+        end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::doc_comment::DocComment;
+    use crate::tags::TagToken;
+
+    fn entry<'a>(source: &'a DocComment, within: &str, name: &str, desc: &str) -> FunctionDocEntry<'a> {
+        FunctionDocEntry {
+            name: name.to_string(),
+            desc: desc.to_string(),
+            within: within.to_string(),
+            params: Vec::new(),
+            returns: Vec::new(),
+            tags: Vec::new(),
+            errors: Vec::new(),
+            function_type: FunctionType::Static,
+            realm: BTreeSet::new(),
+            private: false,
+            unreleased: false,
+            yields: false,
+            ignore: false,
+            since: None,
+            deprecated: None,
+            resolved_links: Vec::new(),
+            overloads: Vec::new(),
+            source,
+        }
+    }
+
+    #[test]
+    fn resolves_a_bare_reference_within_the_same_class() {
+        let source = DocComment::new("");
+        let target = entry(&source, "Widget", "spin", "");
+        let caller = entry(&source, "Widget", "example", "See [spin] for details.");
+
+        let diagnostics = resolve_links(&mut [target, caller]);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reports_an_unresolved_reference() {
+        let source = DocComment::new("");
+        let mut caller = entry(&source, "Widget", "example", "See [missing] for details.");
+
+        let diagnostics = resolve_links(std::slice::from_mut(&mut caller));
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("Unresolved"));
+    }
+
+    #[test]
+    fn does_not_mistake_a_markdown_link_for_an_intra_doc_reference() {
+        let desc = "See [the config docs](./config.md) for more.";
+        let refs = find_references(desc);
+
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn does_not_panic_on_non_ascii_desc() {
+        let refs = find_references("café ☕ [Widget.spin] for details.");
+
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].raw, "Widget.spin");
+    }
+
+    #[test]
+    fn still_finds_a_bracketed_reference_not_followed_by_parens() {
+        let refs = find_references("See [Widget.spin] for details.");
+
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].raw, "Widget.spin");
+    }
+}
@@ -0,0 +1,168 @@
+use crate::diagnostic::Diagnostic;
+use crate::doc_comment::DocComment;
+
+/// Finds raw `http(s)://` spans in `desc` that aren't already wrapped in Markdown link syntax
+/// (`<...>` or `[text](...)`), ported from rustdoc's bare-URL lint.
+///
+/// Offsets in the returned diagnostics are relative to `source`, so they point into the original
+/// comment rather than just `desc`. These are warnings and never abort the build.
+pub fn lint_bare_urls(desc: &str, source: &DocComment) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let base = source.offset_of(desc);
+    let mut i = 0;
+
+    while let Some(rel) = desc[i..].find("http") {
+        let start = i + rel;
+        let after_scheme = &desc[start..];
+        let scheme_len = if after_scheme.starts_with("https://") {
+            8
+        } else if after_scheme.starts_with("http://") {
+            7
+        } else {
+            i = start + 4;
+            continue;
+        };
+
+        let end = start
+            + scheme_len
+            + desc[start + scheme_len..]
+                .find(|c: char| c.is_whitespace() || c == '>' || c == ')')
+                .unwrap_or(desc.len() - start - scheme_len);
+
+        let already_wrapped = start > 0 && matches!(desc.as_bytes()[start - 1], b'<' | b'(');
+
+        if !already_wrapped {
+            diagnostics.push(source.diagnostic_at(
+                base + start,
+                base + end,
+                &format!(
+                    "Bare URL `{}` is not a Markdown link; wrap it in `<...>` or `[text]({})`.",
+                    &desc[start..end],
+                    &desc[start..end]
+                ),
+            ));
+        }
+
+        i = end;
+    }
+
+    diagnostics
+}
+
+/// Scans for `<tag>`...`</tag>` pairs in `desc`, ignoring anything inside fenced or inline code,
+/// and reports tags that are never closed or are closed out of order, ported from rustdoc's
+/// HTML-tag balance checker.
+pub fn lint_unbalanced_html(desc: &str, source: &DocComment) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut stack: Vec<(String, usize, usize)> = Vec::new();
+    let mut in_fence = false;
+    let mut in_inline_code = false;
+    let base = source.offset_of(desc);
+    let mut i = 0;
+
+    while i < desc.len() {
+        if desc[i..].starts_with("```") {
+            in_fence = !in_fence;
+            i += 3;
+            continue;
+        }
+
+        let Some(ch) = desc[i..].chars().next() else {
+            break;
+        };
+
+        if !in_fence && ch == '`' {
+            in_inline_code = !in_inline_code;
+            i += ch.len_utf8();
+            continue;
+        }
+
+        if !in_fence && !in_inline_code && ch == '<' {
+            if let Some(close_rel) = desc[i + 1..].find('>') {
+                let close = i + 1 + close_rel;
+                let inner = &desc[i + 1..close];
+
+                if let Some(name) = inner.strip_prefix('/') {
+                    match stack.last() {
+                        Some((open_name, _, _)) if open_name == name => {
+                            stack.pop();
+                        }
+                        _ => {
+                            diagnostics.push(source.diagnostic_at(
+                                base + i,
+                                base + close + 1,
+                                &format!("Closing tag `</{name}>` does not match the innermost open tag."),
+                            ));
+                        }
+                    }
+                } else if !inner.is_empty() && !inner.ends_with('/') {
+                    let name = inner.split_whitespace().next().unwrap_or(inner).to_string();
+                    stack.push((name, i, close + 1));
+                }
+
+                i = close + 1;
+                continue;
+            }
+        }
+
+        i += ch.len_utf8();
+    }
+
+    for (name, start, end) in stack {
+        diagnostics.push(source.diagnostic_at(
+            base + start,
+            base + end,
+            &format!("HTML tag `<{name}>` is never closed."),
+        ));
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_urls_are_reported() {
+        let source = DocComment::new("");
+        let diagnostics = lint_bare_urls("See https://example.com for more.", &source);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("Bare URL"));
+    }
+
+    #[test]
+    fn urls_already_wrapped_in_a_markdown_link_are_not_reported() {
+        let source = DocComment::new("");
+        let diagnostics = lint_bare_urls("See [here](https://example.com) for more.", &source);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn unclosed_html_tags_are_reported() {
+        let source = DocComment::new("");
+        let diagnostics = lint_unbalanced_html("<b>bold", &source);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("never closed"));
+    }
+
+    #[test]
+    fn balanced_html_tags_are_not_reported() {
+        let source = DocComment::new("");
+        assert!(lint_unbalanced_html("<b>bold</b>", &source).is_empty());
+    }
+
+    #[test]
+    fn does_not_panic_on_non_ascii_desc() {
+        let source = DocComment::new("");
+        let desc = "café ☕ <b>bold";
+
+        let diagnostics = lint_unbalanced_html(desc, &source);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("never closed"));
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+/// Which Luau VM(s) a doc entry applies to, set via the `@server`/`@client` tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Realm {
+    Server,
+    Client,
+}
@@ -0,0 +1,302 @@
+use serde::Serialize;
+
+use crate::diagnostic::Diagnostic;
+use crate::doc_comment::DocComment;
+
+/// The byte span and originating comment shared by every tag, so any tag can build a `Diagnostic`
+/// pointing at exactly where it was written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagToken<'a> {
+    pub source: &'a DocComment,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl<'a> TagToken<'a> {
+    pub fn span(&self) -> (usize, usize) {
+        (self.start, self.end)
+    }
+
+    pub fn diagnostic(&self, message: &str) -> Diagnostic {
+        self.source.diagnostic_at(self.start, self.end, message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParamTag<'a> {
+    pub name: String,
+    pub desc: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReturnTag<'a> {
+    pub desc: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ErrorTag<'a> {
+    pub desc: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DeprecatedTag<'a> {
+    pub desc: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SinceTag<'a> {
+    pub version: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CustomTag<'a> {
+    pub name: String,
+    pub desc: String,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+/// An alternative calling form for a polymorphic function, introduced via `@overload`.
+///
+/// Unlike the primary signature on `FunctionDocEntry`, an overload carries its own independent
+/// `params`/`returns`/`yields`, since Luau functions are often documented with a different
+/// `@param` set depending on which overload is being called.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverloadTag<'a> {
+    /// Optional name used to reference this overload from a later `@overload` tag body.
+    pub name: Option<String>,
+    /// The signature string as written in the tag body, e.g. `(name: string): boolean`.
+    pub signature: String,
+    pub params: Vec<ParamTag<'a>>,
+    pub returns: Vec<ReturnTag<'a>>,
+    pub yields: bool,
+    #[serde(skip)]
+    pub token: TagToken<'a>,
+}
+
+/// The result of parsing one `@overload` tag body: either a full signature, or a bare name
+/// referencing a signature declared by another `@overload` tag on the same entry.
+#[derive(Debug, PartialEq)]
+pub enum OverloadBody<'a> {
+    Resolved(OverloadTag<'a>),
+    Reference {
+        name: Option<String>,
+        reference: String,
+    },
+}
+
+impl<'a> OverloadBody<'a> {
+    /// Parses an `@overload` tag body.
+    ///
+    /// The body is either an optional `name: <label>` line followed by a signature and nested `@param`/`@return`/
+    /// `@yields` lines, or (with no parens and no nested lines) a bare name referencing a
+    /// signature declared by another `@overload` tag, resolved once every tag on the entry has
+    /// been collected.
+    pub fn parse(body: &str, token: &TagToken<'a>) -> Self {
+        let mut lines = body.lines();
+        let mut declared_name = None;
+        let mut first_line = lines.next().unwrap_or("").trim();
+
+        if let Some(rest) = first_line.strip_prefix("name:") {
+            declared_name = Some(rest.trim().to_string());
+            first_line = lines.next().unwrap_or("").trim();
+        }
+
+        let remaining: Vec<&str> = lines.collect();
+        let looks_like_reference = !first_line.is_empty()
+            && !first_line.contains('(')
+            && first_line.chars().all(|c| c.is_alphanumeric() || c == '_')
+            && remaining.iter().all(|line| line.trim().is_empty());
+
+        if looks_like_reference {
+            return OverloadBody::Reference {
+                name: declared_name,
+                reference: first_line.to_string(),
+            };
+        }
+
+        let (params, returns, yields) = parse_nested_tags(&remaining, token);
+
+        OverloadBody::Resolved(OverloadTag {
+            name: declared_name,
+            signature: first_line.to_string(),
+            params,
+            returns,
+            yields,
+            token: token.clone(),
+        })
+    }
+}
+
+fn parse_nested_tags<'a>(
+    lines: &[&str],
+    token: &TagToken<'a>,
+) -> (Vec<ParamTag<'a>>, Vec<ReturnTag<'a>>, bool) {
+    let mut params = Vec::new();
+    let mut returns = Vec::new();
+    let mut yields = false;
+
+    for line in lines {
+        let line = line.trim();
+
+        if let Some(rest) = line.strip_prefix("@param") {
+            let mut parts = rest.trim().splitn(2, char::is_whitespace);
+            let name = parts
+                .next()
+                .unwrap_or("")
+                .trim_end_matches(':')
+                .to_string();
+            let desc = parts.next().unwrap_or("").trim().to_string();
+            params.push(ParamTag {
+                name,
+                desc,
+                token: token.clone(),
+            });
+        } else if let Some(rest) = line.strip_prefix("@return") {
+            returns.push(ReturnTag {
+                desc: rest.trim().to_string(),
+                token: token.clone(),
+            });
+        } else if line.starts_with("@yields") {
+            yields = true;
+        }
+    }
+
+    (params, returns, yields)
+}
+
+/// A single parsed `@tag` line (or block), not yet folded into a `FunctionDocEntry`.
+#[derive(Debug, PartialEq)]
+pub enum Tag<'a> {
+    Param(ParamTag<'a>),
+    Return(ReturnTag<'a>),
+    Deprecated(DeprecatedTag<'a>),
+    Since(SinceTag<'a>),
+    Custom(CustomTag<'a>),
+    Error(ErrorTag<'a>),
+    Private(TagToken<'a>),
+    Unreleased(TagToken<'a>),
+    Yields(TagToken<'a>),
+    Ignore(TagToken<'a>),
+    Server(TagToken<'a>),
+    Client(TagToken<'a>),
+    /// Carries the raw tag token; the body is parsed into an `OverloadBody` via
+    /// `OverloadBody::parse` once the `@overload` text itself has been extracted.
+    Overload(TagToken<'a>, String),
+}
+
+impl<'a> Tag<'a> {
+    /// The byte span this tag was written at, so callers can anchor a diagnostic at it without
+    /// knowing which variant they have.
+    pub fn span(&self) -> (usize, usize) {
+        match self {
+            Tag::Param(tag) => tag.token.span(),
+            Tag::Return(tag) => tag.token.span(),
+            Tag::Deprecated(tag) => tag.token.span(),
+            Tag::Since(tag) => tag.token.span(),
+            Tag::Custom(tag) => tag.token.span(),
+            Tag::Error(tag) => tag.token.span(),
+            Tag::Private(token)
+            | Tag::Unreleased(token)
+            | Tag::Yields(token)
+            | Tag::Ignore(token)
+            | Tag::Server(token)
+            | Tag::Client(token) => token.span(),
+            Tag::Overload(token, _) => token.span(),
+        }
+    }
+
+    pub fn diagnostic(&self, message: &str) -> Diagnostic {
+        match self {
+            Tag::Param(tag) => tag.token.diagnostic(message),
+            Tag::Return(tag) => tag.token.diagnostic(message),
+            Tag::Deprecated(tag) => tag.token.diagnostic(message),
+            Tag::Since(tag) => tag.token.diagnostic(message),
+            Tag::Custom(tag) => tag.token.diagnostic(message),
+            Tag::Error(tag) => tag.token.diagnostic(message),
+            Tag::Private(token)
+            | Tag::Unreleased(token)
+            | Tag::Yields(token)
+            | Tag::Ignore(token)
+            | Tag::Server(token)
+            | Tag::Client(token) => token.diagnostic(message),
+            Tag::Overload(token, _) => token.diagnostic(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_reports_the_tag_tokens_byte_range() {
+        let source = DocComment::new("@private");
+        let tag = Tag::Private(TagToken {
+            source: &source,
+            start: 0,
+            end: 8,
+        });
+
+        assert_eq!(tag.span(), (0, 8));
+    }
+
+    #[test]
+    fn diagnostic_is_anchored_at_the_tags_span() {
+        let source = DocComment::new("@private");
+        let tag = Tag::Private(TagToken {
+            source: &source,
+            start: 0,
+            end: 8,
+        });
+
+        let diagnostic = tag.diagnostic("not used here");
+        assert_eq!((diagnostic.start, diagnostic.end), (0, 8));
+        assert_eq!(diagnostic.message, "not used here");
+    }
+
+    fn overload_token(source: &DocComment) -> TagToken<'_> {
+        TagToken {
+            source,
+            start: 0,
+            end: 1,
+        }
+    }
+
+    #[test]
+    fn parses_inline_overload_with_nested_tags() {
+        let source = DocComment::new("");
+        let body = "name: withCount\n(count: number): boolean\n@param count The number of items\n@return Whether it succeeded\n@yields";
+
+        match OverloadBody::parse(body, &overload_token(&source)) {
+            OverloadBody::Resolved(tag) => {
+                assert_eq!(tag.name.as_deref(), Some("withCount"));
+                assert_eq!(tag.signature, "(count: number): boolean");
+                assert_eq!(tag.params.len(), 1);
+                assert_eq!(tag.params[0].name, "count");
+                assert_eq!(tag.returns.len(), 1);
+                assert!(tag.yields);
+            }
+            OverloadBody::Reference { .. } => panic!("expected a resolved overload"),
+        }
+    }
+
+    #[test]
+    fn parses_bare_name_as_a_reference() {
+        let source = DocComment::new("");
+        match OverloadBody::parse("withCount", &overload_token(&source)) {
+            OverloadBody::Reference { reference, .. } => assert_eq!(reference, "withCount"),
+            OverloadBody::Resolved(_) => panic!("expected a reference"),
+        }
+    }
+}